@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Messages that flow through the app's central update loop.
+///
+/// Panes and pages translate raw terminal events into `Action`s in their
+/// `handle_key_events`/`handle_mouse_events` implementations, and react to
+/// `Action`s (their own or ones raised by siblings) in `update`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+  Tick,
+  Render,
+  Resize(u16, u16),
+  Suspend,
+  Resume,
+  Quit,
+  Refresh,
+  Error(String),
+  Update,
+  Up,
+  Down,
+  Left,
+  Right,
+  Submit,
+  FocusNext,
+  FocusPrev,
+  /// Cycle `State::active_server_index` forward/backward through the spec's
+  /// `servers` list, so a spec that advertises more than one (prod vs.
+  /// staging, ...) isn't stuck always hitting `servers[0]`.
+  ServerNext,
+  ServerPrev,
+  /// A response came back for the in-flight request, forwarded from the
+  /// background task that sent it (see `RequestPane::submit`).
+  Response { status: u16, headers: Vec<(String, String)>, content_type: Option<String>, body: String },
+  /// Emitted by the event loop at a fixed ~80ms cadence while at least one
+  /// request is in flight, so
+  /// panes can advance their `Spinner` without burning CPU while idle.
+  SpinnerTick,
+}
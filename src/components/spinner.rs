@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// A small braille-cycle spinner, advanced one frame per `Action::SpinnerTick`
+/// while its owner has a request in flight.
+/// Panes splice `frame()` into their `Block` title, e.g. `"Request ⠹"`.
+pub struct Spinner {
+  frames: [char; 10],
+  index: usize,
+}
+
+impl Default for Spinner {
+  fn default() -> Self {
+    Self { frames: ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'], index: 0 }
+  }
+}
+
+impl Spinner {
+  /// Cadence the event loop should emit `Action::SpinnerTick` at while any
+  /// task is pending. Idle panes never advance the spinner, so they cost
+  /// nothing when there's nothing in flight.
+  pub const TICK_RATE: Duration = Duration::from_millis(80);
+
+  pub fn tick(&mut self) {
+    self.index = (self.index + 1) % self.frames.len();
+  }
+
+  pub fn reset(&mut self) {
+    self.index = 0;
+  }
+
+  pub fn frame(&self) -> char {
+    self.frames[self.index]
+  }
+}
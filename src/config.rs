@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+use syntect::highlighting::{Theme, ThemeSet};
+
+fn default_highlight_theme() -> String {
+  "Solarized (dark)".to_string()
+}
+
+/// User-facing configuration, merged from defaults and an optional config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+  /// Name of the bundled (or user-supplied, via `theme_folder`) `syntect` theme
+  /// used to highlight response bodies and fenced code blocks in descriptions.
+  #[serde(default = "default_highlight_theme")]
+  pub highlight_theme: String,
+  /// Optional folder of `.tmTheme` files loaded alongside the bundled themes,
+  /// so `highlight_theme` can also name a user-supplied color scheme.
+  #[serde(default)]
+  pub theme_folder: Option<PathBuf>,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Self { highlight_theme: default_highlight_theme(), theme_folder: None }
+  }
+}
+
+impl Config {
+  /// Resolve `highlight_theme` to a loaded `syntect` `Theme`, falling back to
+  /// the bundled "Solarized (dark)" theme if the configured name isn't found
+  /// in the defaults or in `theme_folder`.
+  pub fn highlight_theme(&self) -> Result<Theme> {
+    let mut theme_set = ThemeSet::load_defaults();
+    if let Some(theme_folder) = &self.theme_folder {
+      theme_set.add_from_folder(theme_folder)?;
+    }
+
+    Ok(
+      theme_set
+        .themes
+        .get(&self.highlight_theme)
+        .or_else(|| theme_set.themes.get(&default_highlight_theme()))
+        .cloned()
+        .unwrap_or_default(),
+    )
+  }
+}
@@ -0,0 +1,65 @@
+use oas3::{spec::Operation, Spec};
+
+/// Shared state for the home page, read by every pane via `Arc<RwLock<State>>`.
+pub struct State {
+  pub openapi_spec: Spec,
+  pub active_operation_index: usize,
+  /// Index into `openapi_spec.servers` used to build the base URL for requests,
+  /// since a spec may advertise more than one (e.g. prod vs. staging).
+  pub active_server_index: usize,
+}
+
+impl State {
+  pub fn new(openapi_spec: Spec) -> Self {
+    Self { openapi_spec, active_operation_index: 0, active_server_index: 0 }
+  }
+
+  /// The base URL of the currently selected server, if the spec declares any.
+  pub fn base_url(&self) -> Option<&str> {
+    self.openapi_spec.servers.get(self.active_server_index).map(|server| server.url.as_str())
+  }
+
+  /// Moves `active_server_index` forward (`delta > 0`) or backward (`delta <
+  /// 0`) through `servers`, wrapping around at either end. A no-op on a spec
+  /// with no servers.
+  pub fn cycle_server(&mut self, delta: isize) {
+    let count = self.openapi_spec.servers.len();
+    if count == 0 {
+      return;
+    }
+    let next = (self.active_server_index as isize + delta).rem_euclid(count as isize);
+    self.active_server_index = next as usize;
+  }
+
+  /// All operations in the spec, flattened to `(path, METHOD, Operation)` triples
+  /// in a stable order so an index into this list can be kept around (e.g. by
+  /// `active_operation_index` or the fuzzy finder's result list).
+  pub fn operations(&self) -> Vec<(&str, &str, &Operation)> {
+    let Some(paths) = &self.openapi_spec.paths else {
+      return vec![];
+    };
+
+    let mut operations = vec![];
+    for (path, item) in paths.iter() {
+      for (method, operation) in [
+        ("GET", &item.get),
+        ("PUT", &item.put),
+        ("POST", &item.post),
+        ("DELETE", &item.delete),
+        ("OPTIONS", &item.options),
+        ("HEAD", &item.head),
+        ("PATCH", &item.patch),
+        ("TRACE", &item.trace),
+      ] {
+        if let Some(operation) = operation {
+          operations.push((path.as_str(), method, operation));
+        }
+      }
+    }
+    operations
+  }
+
+  pub fn active_operation(&self) -> Option<(&str, &str, &Operation)> {
+    self.operations().into_iter().nth(self.active_operation_index)
+  }
+}
@@ -0,0 +1,279 @@
+use std::{
+  sync::{Arc, RwLock},
+  time::{Duration, Instant},
+};
+
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
+use ratatui::{
+  prelude::*,
+  widgets::{block::*, *},
+};
+
+use crate::{
+  action::Action,
+  pages::home::State,
+  panes::Pane,
+  tui::{EventResponse, Frame},
+};
+
+/// How long the query can sit unchanged before a match pass is allowed to run.
+///
+/// Matching against a multi-thousand-operation spec on every keystroke makes
+/// typing feel laggy, so instead we wait for a short pause in typing (mirroring
+/// the idle-debounced pickers found in most editor TUIs) before recomputing.
+const DEBOUNCE: Duration = Duration::from_millis(275);
+
+/// A single scored candidate in the result list.
+struct FinderResult {
+  operation_index: usize,
+  label: String,
+  score: i64,
+}
+
+/// Pop-up fuzzy finder over every operation in the loaded spec.
+///
+/// Opened with `/`, typing narrows `query` and Up/Down/Enter move through and
+/// commit `results`. Matching itself is debounced: keystrokes only flip the
+/// `dirty` flag and stamp `last_input_at`, and the actual scoring pass happens
+/// on a `Tick` once the query has been quiet for `DEBOUNCE`, so the previously
+/// computed `results` stay on screen without flickering while the user types.
+#[derive(Default)]
+pub struct FinderPane {
+  focused: bool,
+  focused_border_style: Style,
+  state: Arc<RwLock<State>>,
+  visible: bool,
+  query: String,
+  dirty: bool,
+  last_input_at: Option<Instant>,
+  results: Vec<FinderResult>,
+  selected: usize,
+}
+
+impl FinderPane {
+  pub fn new(state: Arc<RwLock<State>>, focused: bool, focused_border_style: Style) -> Self {
+    Self { state, focused, focused_border_style, ..Default::default() }
+  }
+
+  fn border_style(&self) -> Style {
+    match self.focused {
+      true => self.focused_border_style,
+      false => Style::default(),
+    }
+  }
+
+  fn border_type(&self) -> BorderType {
+    match self.focused {
+      true => BorderType::Thick,
+      false => BorderType::Plain,
+    }
+  }
+
+  fn open(&mut self) {
+    self.visible = true;
+    self.query.clear();
+    self.dirty = true;
+    self.last_input_at = Some(Instant::now());
+  }
+
+  fn close(&mut self) {
+    self.visible = false;
+    self.query.clear();
+    self.results.clear();
+    self.selected = 0;
+  }
+
+  /// Substring-based fuzzy score: every query char must appear in order in the
+  /// candidate, with bonuses for contiguous runs and an early match. Higher is
+  /// better; `None` means the query doesn't match at all.
+  fn score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+      return Some(0);
+    }
+    let candidate_lower = candidate.to_lowercase();
+    let query_lower = query.to_lowercase();
+
+    let mut score: i64 = 0;
+    let mut candidate_chars = candidate_lower.char_indices();
+    let mut run = 0i64;
+    for q in query_lower.chars() {
+      loop {
+        match candidate_chars.next() {
+          Some((idx, c)) if c == q => {
+            run += 1;
+            score += run + if idx == 0 { 5 } else { 0 };
+            break;
+          },
+          Some(_) => {
+            run = 0;
+          },
+          None => return None,
+        }
+      }
+    }
+    Some(score)
+  }
+
+  fn run_match(&mut self) {
+    let state = self.state.read().unwrap();
+    let mut results: Vec<FinderResult> = state
+      .operations()
+      .iter()
+      .enumerate()
+      .filter_map(|(operation_index, (path, method, operation))| {
+        let summary = operation.summary.clone().unwrap_or_default();
+        let operation_id = operation.operation_id.clone().unwrap_or_default();
+        let label = format!("{method} {path} {summary}");
+        let candidate = format!("{label} {operation_id}");
+        Self::score(&self.query, &candidate).map(|score| FinderResult { operation_index, label, score })
+      })
+      .collect();
+    drop(state);
+
+    results.sort_by(|a, b| b.score.cmp(&a.score));
+    results.truncate(200);
+    self.results = results;
+    self.selected = self.selected.min(self.results.len().saturating_sub(1));
+  }
+}
+
+impl Pane for FinderPane {
+  fn init(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn focus(&mut self) -> Result<()> {
+    self.focused = true;
+    self.open();
+    Ok(())
+  }
+
+  fn unfocus(&mut self) -> Result<()> {
+    self.focused = false;
+    self.close();
+    Ok(())
+  }
+
+  fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<EventResponse<Action>>> {
+    if !self.visible {
+      return Ok(None);
+    }
+
+    match key.code {
+      KeyCode::Esc => return Ok(Some(EventResponse::Stop(Action::FocusPrev))),
+      KeyCode::Enter => {
+        if let Some(result) = self.results.get(self.selected) {
+          self.state.write().unwrap().active_operation_index = result.operation_index;
+          return Ok(Some(EventResponse::Stop(Action::Update)));
+        }
+      },
+      KeyCode::Down => self.selected = self.selected.saturating_add(1).min(self.results.len().saturating_sub(1)),
+      KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+      KeyCode::Backspace => {
+        self.query.pop();
+        self.dirty = true;
+        self.last_input_at = Some(Instant::now());
+      },
+      KeyCode::Char(c) => {
+        self.query.push(c);
+        self.dirty = true;
+        self.last_input_at = Some(Instant::now());
+      },
+      _ => {},
+    }
+
+    Ok(None)
+  }
+
+  #[allow(unused_variables)]
+  fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<EventResponse<Action>>> {
+    Ok(None)
+  }
+
+  fn update(&mut self, action: Action) -> Result<Option<Action>> {
+    match action {
+      Action::Tick => {
+        if self.dirty {
+          if let Some(last_input_at) = self.last_input_at {
+            if last_input_at.elapsed() >= DEBOUNCE {
+              self.run_match();
+              self.dirty = false;
+            }
+          }
+        }
+      },
+      _ => {},
+    }
+
+    Ok(None)
+  }
+
+  fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
+    if !self.visible {
+      return Ok(());
+    }
+
+    let inner_margin = Margin { horizontal: 1, vertical: 1 };
+    let inner = area.inner(&inner_margin);
+    let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).split(inner);
+
+    frame.render_widget(Paragraph::new(Line::from(vec![Span::raw("/"), Span::raw(self.query.as_str())])), layout[0]);
+
+    let items = self.results.iter().map(|result| Text::raw(result.label.clone()));
+    let mut list_state = ListState::default().with_selected(Some(self.selected));
+    frame.render_stateful_widget(
+      List::new(items).highlight_symbol(symbols::scrollbar::HORIZONTAL.end).highlight_spacing(HighlightSpacing::Always),
+      layout[1],
+      &mut list_state,
+    );
+
+    frame.render_widget(
+      Block::default()
+        .title("Find Operation")
+        .borders(Borders::ALL)
+        .border_style(self.border_style())
+        .border_type(self.border_type()),
+      area,
+    );
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty_query_matches_everything_with_zero_score() {
+    assert_eq!(FinderPane::score("", "GET /pets"), Some(0));
+  }
+
+  #[test]
+  fn in_order_subsequence_matches() {
+    assert!(FinderPane::score("gpt", "GET /pets").is_some());
+  }
+
+  #[test]
+  fn out_of_order_characters_do_not_match() {
+    assert_eq!(FinderPane::score("tpg", "GET /pets"), None);
+  }
+
+  #[test]
+  fn characters_missing_from_the_candidate_do_not_match() {
+    assert_eq!(FinderPane::score("xyz", "GET /pets"), None);
+  }
+
+  #[test]
+  fn is_case_insensitive() {
+    assert!(FinderPane::score("PETS", "get /pets").is_some());
+  }
+
+  #[test]
+  fn a_contiguous_match_scores_higher_than_a_scattered_one() {
+    let contiguous = FinderPane::score("pets", "GET /pets").unwrap();
+    let scattered = FinderPane::score("pets", "POST /p/e/t/s").unwrap();
+    assert!(contiguous > scattered, "{contiguous} should outscore {scattered}");
+  }
+}
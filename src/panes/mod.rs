@@ -0,0 +1,30 @@
+pub mod finder;
+pub mod request;
+pub mod response;
+
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::prelude::*;
+
+use crate::{
+  action::Action,
+  tui::{EventResponse, Frame},
+};
+
+/// A focusable region of the UI that owns its own state and renders into a
+/// `Rect` handed to it by the page that contains it.
+pub trait Pane {
+  fn init(&mut self) -> Result<()>;
+
+  fn focus(&mut self) -> Result<()>;
+
+  fn unfocus(&mut self) -> Result<()>;
+
+  fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<EventResponse<Action>>>;
+
+  fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<EventResponse<Action>>>;
+
+  fn update(&mut self, action: Action) -> Result<Option<Action>>;
+
+  fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()>;
+}
@@ -0,0 +1,351 @@
+pub mod tree;
+
+use std::sync::{Arc, RwLock};
+
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
+use oas3::{
+  spec::{ObjectOrReference, RequestBody},
+  Schema,
+};
+use ratatui::{
+  prelude::*,
+  widgets::{block::*, *},
+};
+use syntect::highlighting::Theme;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+  action::Action,
+  components::spinner::Spinner,
+  pages::home::State,
+  panes::{
+    request::tree::{SchemaNodeKind, SchemaTree},
+    Pane,
+  },
+  tui::{EventResponse, Frame},
+  utils::markdown,
+};
+
+#[derive(Default)]
+pub struct RequestPane {
+  focused: bool,
+  focused_border_style: Style,
+  state: Arc<RwLock<State>>,
+  request_body: Option<RequestBody>,
+  request_body_description: Vec<Line<'static>>,
+  request_schema: Option<Schema>,
+  schema_tree: SchemaTree,
+  highlighter_syntax_set: syntect::parsing::SyntaxSet,
+  highlight_theme: Theme,
+  action_tx: Option<UnboundedSender<Action>>,
+  spinner: Spinner,
+  pending: bool,
+}
+
+impl RequestPane {
+  pub fn new(state: Arc<RwLock<State>>, focused: bool, focused_border_style: Style, highlight_theme: Theme) -> Self {
+    Self {
+      state,
+      focused,
+      focused_border_style,
+      request_body: None,
+      request_body_description: vec![],
+      request_schema: None,
+      schema_tree: SchemaTree::default(),
+      highlighter_syntax_set: syntect::parsing::SyntaxSet::load_defaults_newlines(),
+      highlight_theme,
+      action_tx: None,
+      spinner: Spinner::default(),
+      pending: false,
+    }
+  }
+
+  fn title(&self) -> String {
+    match self.pending {
+      true => format!("Request {}", self.spinner.frame()),
+      false => "Request".to_string(),
+    }
+  }
+
+  /// Wires up the channel the app's event loop drains, so the background task
+  /// spawned by `submit` can post its result back as an `Action` without
+  /// blocking the UI thread.
+  pub fn register_action_handler(&mut self, tx: UnboundedSender<Action>) {
+    self.action_tx = Some(tx);
+  }
+
+  /// Builds and sends the active operation's request on a background task,
+  /// seeding the body from the request schema's `example` and substituting
+  /// any `{param}` placeholders in the path with example/default values.
+  fn submit(&mut self) -> Result<()> {
+    let Some(action_tx) = self.action_tx.clone() else { return Ok(()) };
+
+    let (method, url, query_params, body) = {
+      let state = self.state.read().unwrap();
+      // Both of these bail before anything is spawned, so without reporting
+      // back here `ResponsePane`/`Tui` would see the `Action::Submit` keypress,
+      // mark themselves pending, and then never hear a `Response`/`Error` to
+      // clear it — stuck spinner, `SpinnerTick` ticking forever.
+      let Some(base_url) = state.base_url() else {
+        let _ = action_tx.send(Action::Error("no server configured for this spec".to_string()));
+        return Ok(());
+      };
+      let Some((path, method, operation)) = state.active_operation() else {
+        let _ = action_tx.send(Action::Error("no operation selected".to_string()));
+        return Ok(());
+      };
+
+      let mut resolved_path = path.to_string();
+      let mut query_params: Vec<(String, String)> = vec![];
+      for parameter in &operation.parameters {
+        if let Ok(parameter) = parameter.resolve(&state.openapi_spec) {
+          // The schema's `example` seeds the value; fall back to `default`
+          // rather than a made-up literal, since a path/query param might not
+          // be numeric at all (a string id, an enum, a uuid, ...).
+          let value = parameter.schema.as_ref().and_then(|s| s.example.clone().or_else(|| s.default.clone()));
+          match parameter.location {
+            oas3::spec::ParameterIn::Path => {
+              if let Some(value) = &value {
+                resolved_path = resolved_path.replace(&format!("{{{}}}", parameter.name), &value.to_string());
+              }
+            },
+            oas3::spec::ParameterIn::Query => {
+              if let Some(value) = value {
+                query_params.push((parameter.name.clone(), value.to_string()));
+              }
+            },
+            _ => {},
+          }
+        }
+      }
+
+      let body = self.request_schema.as_ref().and_then(|schema| schema.example.clone().or_else(|| schema.default.clone()));
+      (method.to_string(), format!("{}{}", base_url.trim_end_matches('/'), resolved_path), query_params, body)
+    };
+
+    tokio::spawn(async move {
+      let client = reqwest::Client::new();
+      let method = reqwest::Method::from_bytes(method.as_bytes()).unwrap_or(reqwest::Method::GET);
+      let mut request = client.request(method, url).query(&query_params);
+      if let Some(body) = &body {
+        request = request.json(body);
+      }
+
+      let action = match request.send().await {
+        Ok(response) => {
+          let status = response.status().as_u16();
+          let content_type =
+            response.headers().get(reqwest::header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).map(str::to_string);
+          let headers =
+            response.headers().iter().map(|(k, v)| (k.to_string(), v.to_str().unwrap_or_default().to_string())).collect();
+          match response.text().await {
+            Ok(body) => Action::Response { status, headers, content_type, body },
+            Err(err) => Action::Error(err.to_string()),
+          }
+        },
+        Err(err) => Action::Error(err.to_string()),
+      };
+      let _ = action_tx.send(action);
+    });
+
+    self.pending = true;
+    Ok(())
+  }
+
+  fn border_style(&self) -> Style {
+    match self.focused {
+      true => self.focused_border_style,
+      false => Style::default(),
+    }
+  }
+
+  fn border_type(&self) -> BorderType {
+    match self.focused {
+      true => BorderType::Thick,
+      false => BorderType::Plain,
+    }
+  }
+
+  fn init_request_schema(&mut self) -> Result<()> {
+    let state = self.state.read().unwrap();
+    self.request_body = None;
+    self.request_body_description = vec![];
+    self.request_schema = None;
+    self.schema_tree.clear();
+    if let Some((_path, _method, operation)) = state.active_operation() {
+      if let Some(oor) = &operation.request_body {
+        let resolved_oor = oor.resolve(&state.openapi_spec)?;
+
+        if let Some(description) = &resolved_oor.description {
+          self.request_body_description =
+            markdown::render(description, &self.highlighter_syntax_set, &self.highlight_theme);
+        }
+
+        if let Some(req_content) = resolved_oor.content.get("application/json") {
+          let request_schema = req_content.schema(&state.openapi_spec)?;
+          self.schema_tree.set_root("body", &ObjectOrReference::Object(request_schema.clone()));
+          self.request_schema = Some(request_schema);
+        }
+        self.request_body = Some(resolved_oor);
+      }
+    }
+    Ok(())
+  }
+}
+impl Pane for RequestPane {
+  fn init(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn focus(&mut self) -> Result<()> {
+    self.focused = true;
+    Ok(())
+  }
+
+  fn unfocus(&mut self) -> Result<()> {
+    self.focused = false;
+    Ok(())
+  }
+
+  fn handle_key_events(&mut self, key: KeyEvent) -> Result<Option<EventResponse<Action>>> {
+    // Toggling is bound to `Action::Left`/`Action::Right` in `update` rather than
+    // intercepted here, so Enter is left free to keep producing `Action::Submit`
+    // through the app's normal keymap dispatch. `[`/`]` cycle the active server,
+    // since `Left`/`Right` are already spoken for by the schema tree.
+    match key.code {
+      KeyCode::Char('[') => Ok(Some(EventResponse::Stop(Action::ServerPrev))),
+      KeyCode::Char(']') => Ok(Some(EventResponse::Stop(Action::ServerNext))),
+      _ => Ok(None),
+    }
+  }
+
+  #[allow(unused_variables)]
+  fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<EventResponse<Action>>> {
+    Ok(None)
+  }
+
+  fn update(&mut self, action: Action) -> Result<Option<Action>> {
+    match action {
+      Action::Update => {
+        self.init_request_schema()?;
+      },
+      Action::Down => {
+        self.schema_tree.move_down();
+      },
+      Action::Up => {
+        self.schema_tree.move_up();
+      },
+      Action::Left | Action::Right => {
+        let state = self.state.read().unwrap();
+        self.schema_tree.toggle_selected(&state.openapi_spec);
+      },
+      Action::ServerNext => {
+        self.state.write().unwrap().cycle_server(1);
+      },
+      Action::ServerPrev => {
+        self.state.write().unwrap().cycle_server(-1);
+      },
+      Action::Submit => {
+        self.submit()?;
+      },
+      Action::SpinnerTick if self.pending => {
+        self.spinner.tick();
+      },
+      Action::Response { .. } | Action::Error(_) => {
+        self.pending = false;
+        self.spinner.reset();
+      },
+      _ => {},
+    }
+
+    Ok(None)
+  }
+
+  fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
+    if let Some(request_body) = &self.request_body {
+      let inner_margin: Margin = Margin { horizontal: 1, vertical: 1 };
+
+      let mut inner = area.inner(&inner_margin);
+
+      let server_label = {
+        let state = self.state.read().unwrap();
+        let server_count = state.openapi_spec.servers.len();
+        (server_count > 1).then(|| {
+          format!(
+            "Server {}/{}: {} ([/] to change)",
+            state.active_server_index + 1,
+            server_count,
+            state.base_url().unwrap_or_default()
+          )
+        })
+      };
+      if let Some(server_label) = server_label {
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).split(inner);
+        frame.render_widget(Paragraph::new(server_label).style(Style::default().dark_gray()), layout[0]);
+        inner = layout[1];
+      }
+
+      if !self.request_body_description.is_empty() {
+        let description = Paragraph::new(self.request_body_description.clone()).wrap(Wrap { trim: false });
+        // `line_count` accounts for wrapping at this width, unlike the raw
+        // `Vec<Line>` length, so long lines don't get clipped out of view.
+        let description_height = description.line_count(inner.width) as u16;
+        let layout =
+          Layout::vertical([Constraint::Length(description_height), Constraint::Fill(1)]).split(inner);
+        frame.render_widget(description, layout[0]);
+        inner = layout[1];
+      }
+
+      let media_types: Vec<String> = request_body.content.keys().map(|item| item.to_string()).collect();
+
+      frame.render_widget(
+        Tabs::new(media_types)
+          .style(Style::default().dark_gray())
+          .highlight_style(Style::default().white().add_modifier(Modifier::BOLD | Modifier::UNDERLINED))
+          .select(0)
+          .divider(symbols::DOT)
+          .padding(" ", " "),
+        inner,
+      );
+
+      let inner_margin: Margin = Margin { horizontal: 1, vertical: 1 };
+      let mut inner = inner.inner(&inner_margin);
+      inner.height = inner.height.saturating_add(1);
+      let lines = self.schema_tree.visible_rows().into_iter().map(|(depth, node)| {
+        let indicator = match (&node.kind, node.recursive) {
+          (_, true) => "\u{26a0}",
+          (SchemaNodeKind::Leaf, _) => " ",
+          (_, _) if node.expanded => "\u{25be}",
+          _ => "\u{25b8}",
+        };
+        Line::from(vec![
+          Span::raw("  ".repeat(depth)),
+          Span::styled(format!("{indicator} "), Style::default().dark_gray()),
+          Span::raw(node.name.clone()),
+          Span::raw(": "),
+          Span::styled(node.type_label.clone(), Style::default().dim()),
+        ])
+      });
+      let mut list_state = ListState::default().with_selected(Some(self.schema_tree.selected));
+
+      frame.render_stateful_widget(
+        List::new(lines)
+          .highlight_symbol(symbols::scrollbar::HORIZONTAL.end)
+          .highlight_spacing(HighlightSpacing::Always),
+        inner,
+        &mut list_state,
+      );
+    }
+    frame.render_widget(
+      Block::default()
+        .title(self.title())
+        .borders(Borders::ALL)
+        .border_style(self.border_style())
+        .border_type(self.border_type()),
+      area,
+    );
+
+    Ok(())
+  }
+}
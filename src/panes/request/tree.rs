@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+
+use oas3::{
+  spec::{ObjectOrReference, SchemaType},
+  Schema, Spec,
+};
+
+/// What a node's children (if any) represent.
+#[derive(Debug, Clone)]
+pub enum SchemaNodeKind {
+  Object,
+  Array,
+  /// An `allOf`/`oneOf`/`anyOf` composition; the `&'static str` is the keyword.
+  Group(&'static str),
+  /// A `$ref` property, shown collapsed with its ref path until expanded.
+  Ref(String),
+  Leaf,
+}
+
+/// One row of the schema outline. Built eagerly for inline object/array/group
+/// schemas; `$ref` nodes are left childless until the user expands them, so a
+/// deeply cross-referenced spec doesn't get fully walked up front.
+pub struct SchemaNode {
+  pub name: String,
+  pub type_label: String,
+  pub kind: SchemaNodeKind,
+  pub expanded: bool,
+  /// Set when expanding would re-enter a `$ref` already open higher up the
+  /// same branch; the node stays collapsed instead of recursing forever.
+  pub recursive: bool,
+  pub children: Vec<SchemaNode>,
+}
+
+impl SchemaNode {
+  pub fn build(name: impl Into<String>, property: &ObjectOrReference<Schema>) -> Self {
+    match property {
+      ObjectOrReference::Ref { ref_path } => Self {
+        name: name.into(),
+        type_label: ref_path.rsplit('/').next().unwrap_or(ref_path).to_string(),
+        kind: SchemaNodeKind::Ref(ref_path.clone()),
+        expanded: false,
+        recursive: false,
+        children: vec![],
+      },
+      ObjectOrReference::Object(schema) => Self::from_schema(name.into(), schema),
+    }
+  }
+
+  fn from_schema(name: String, schema: &Schema) -> Self {
+    let mut children = vec![];
+    let kind = if let Some(properties) = &schema.properties {
+      for (prop_name, prop_schema) in properties {
+        children.push(Self::build(prop_name.clone(), prop_schema));
+      }
+      SchemaNodeKind::Object
+    } else if let Some(items) = schema.items.as_deref() {
+      children.push(Self::build("[]", items));
+      SchemaNodeKind::Array
+    } else if let Some((keyword, members)) = [("allOf", &schema.all_of), ("oneOf", &schema.one_of), ("anyOf", &schema.any_of)]
+      .into_iter()
+      .find(|(_, members)| !members.is_empty())
+    {
+      for (index, member) in members.iter().enumerate() {
+        children.push(Self::build(format!("{keyword}[{index}]"), member));
+      }
+      SchemaNodeKind::Group(keyword)
+    } else {
+      SchemaNodeKind::Leaf
+    };
+
+    let type_label = schema
+      .schema_type
+      .as_ref()
+      .map(|schema_type| match schema_type {
+        SchemaType::Boolean => "boolean",
+        SchemaType::Integer => "integer",
+        SchemaType::Number => "number",
+        SchemaType::String => "string",
+        SchemaType::Array => "array",
+        SchemaType::Object => "object",
+      })
+      .unwrap_or("object")
+      .to_string();
+
+    Self { name, type_label, kind, expanded: false, recursive: false, children }
+  }
+}
+
+/// A foldable view over a `SchemaNode` tree: owns the tree plus the flattened
+/// list of currently-visible rows used for Up/Down navigation and rendering.
+#[derive(Default)]
+pub struct SchemaTree {
+  root: Option<SchemaNode>,
+  rows: Vec<Vec<usize>>,
+  pub selected: usize,
+}
+
+impl SchemaTree {
+  pub fn set_root(&mut self, name: impl Into<String>, property: &ObjectOrReference<Schema>) {
+    let mut root = SchemaNode::build(name, property);
+    root.expanded = true;
+    self.root = Some(root);
+    self.selected = 0;
+    self.rebuild_rows();
+  }
+
+  pub fn clear(&mut self) {
+    self.root = None;
+    self.rows.clear();
+    self.selected = 0;
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.root.is_none()
+  }
+
+  /// `(depth, node)` pairs for every visible row, in display order.
+  pub fn visible_rows(&self) -> Vec<(usize, &SchemaNode)> {
+    self.rows.iter().filter_map(|path| self.node_at(path).map(|node| (path.len(), node))).collect()
+  }
+
+  pub fn move_down(&mut self) {
+    self.selected = self.selected.saturating_add(1).min(self.rows.len().saturating_sub(1));
+  }
+
+  pub fn move_up(&mut self) {
+    self.selected = self.selected.saturating_sub(1);
+  }
+
+  /// Collapse/expand the selected node, resolving a `$ref` against `spec` the
+  /// first time it's opened. Guards against a schema that (directly or
+  /// transitively) references itself by tracking which `$ref` paths are
+  /// already open along the branch leading to the node being expanded.
+  pub fn toggle_selected(&mut self, spec: &Spec) {
+    let Some(path) = self.rows.get(self.selected).cloned() else { return };
+    let refs_on_path = self.ancestor_refs(&path);
+
+    let Some(node) = self.node_at_mut(&path) else { return };
+    match &node.kind {
+      SchemaNodeKind::Ref(ref_path) if node.children.is_empty() => {
+        if refs_on_path.contains(ref_path) {
+          node.recursive = true;
+        } else if let Ok(resolved) = (ObjectOrReference::<Schema>::Ref { ref_path: ref_path.clone() }).resolve(spec) {
+          let resolved = SchemaNode::from_schema(node.name.clone(), &resolved);
+          node.children = resolved.children;
+          node.type_label = resolved.type_label;
+          node.expanded = true;
+        }
+      },
+      _ => node.expanded = !node.expanded,
+    }
+    self.rebuild_rows();
+  }
+
+  fn ancestor_refs(&self, path: &[usize]) -> HashSet<String> {
+    let mut refs = HashSet::new();
+    let mut node = self.root.as_ref();
+    for &index in path {
+      if let Some(current) = node {
+        if let SchemaNodeKind::Ref(ref_path) = &current.kind {
+          refs.insert(ref_path.clone());
+        }
+        node = current.children.get(index);
+      }
+    }
+    refs
+  }
+
+  fn node_at(&self, path: &[usize]) -> Option<&SchemaNode> {
+    let mut node = self.root.as_ref()?;
+    for &index in path {
+      node = node.children.get(index)?;
+    }
+    Some(node)
+  }
+
+  fn node_at_mut(&mut self, path: &[usize]) -> Option<&mut SchemaNode> {
+    let mut node = self.root.as_mut()?;
+    for &index in path {
+      node = node.children.get_mut(index)?;
+    }
+    Some(node)
+  }
+
+  fn rebuild_rows(&mut self) {
+    self.rows.clear();
+    if let Some(root) = &self.root {
+      Self::collect_rows(root, &mut vec![], &mut self.rows);
+    }
+    self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+  }
+
+  fn collect_rows(node: &SchemaNode, path: &mut Vec<usize>, rows: &mut Vec<Vec<usize>>) {
+    rows.push(path.clone());
+    if node.expanded {
+      for (index, child) in node.children.iter().enumerate() {
+        path.push(index);
+        Self::collect_rows(child, path, rows);
+        path.pop();
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const MINIMAL_SPEC: &str = "
+openapi: 3.0.0
+info:
+  title: test
+  version: '1'
+paths: {}
+components:
+  schemas:
+    Node:
+      type: object
+      properties:
+        next:
+          $ref: '#/components/schemas/Node'
+";
+
+  fn minimal_spec() -> Spec {
+    serde_yaml::from_str(MINIMAL_SPEC).unwrap()
+  }
+
+  fn ref_node(name: &str, ref_path: &str) -> SchemaNode {
+    SchemaNode {
+      name: name.to_string(),
+      type_label: ref_path.rsplit('/').next().unwrap_or(ref_path).to_string(),
+      kind: SchemaNodeKind::Ref(ref_path.to_string()),
+      expanded: false,
+      recursive: false,
+      children: vec![],
+    }
+  }
+
+  #[test]
+  fn toggling_a_ref_node_resolves_and_expands_it() {
+    let mut tree = SchemaTree::default();
+    tree.root = Some(ref_node("root", "#/components/schemas/Node"));
+    tree.rebuild_rows();
+
+    tree.toggle_selected(&minimal_spec());
+
+    let root = tree.root.as_ref().unwrap();
+    assert!(root.expanded);
+    assert!(!root.recursive);
+    assert_eq!(root.children.len(), 1);
+    assert_eq!(root.children[0].name, "next");
+  }
+
+  #[test]
+  fn toggling_a_ref_that_reappears_on_its_own_ancestor_path_is_marked_recursive_instead_of_expanding() {
+    let mut tree = SchemaTree::default();
+    let mut root = ref_node("root", "#/components/schemas/Node");
+    // Simulate having already expanded `root` once: it now has a child `next`
+    // that points right back at the same `$ref`, so opening `next` would
+    // recurse into `root` forever without the ancestor-ref guard.
+    root.expanded = true;
+    root.children = vec![ref_node("next", "#/components/schemas/Node")];
+    tree.root = Some(root);
+    tree.rebuild_rows();
+    tree.selected = 1;
+
+    tree.toggle_selected(&minimal_spec());
+
+    let next = &tree.root.as_ref().unwrap().children[0];
+    assert!(next.recursive);
+    assert!(next.children.is_empty());
+    assert!(!next.expanded);
+  }
+
+  #[test]
+  fn ancestor_refs_collects_every_ref_path_from_root_to_the_given_node() {
+    let mut tree = SchemaTree::default();
+    let mut root = ref_node("root", "#/components/schemas/Node");
+    root.expanded = true;
+    root.children = vec![ref_node("next", "#/components/schemas/Node")];
+    tree.root = Some(root);
+
+    let refs = tree.ancestor_refs(&[0]);
+
+    assert_eq!(refs.len(), 1);
+    assert!(refs.contains("#/components/schemas/Node"));
+  }
+}
@@ -0,0 +1,189 @@
+use color_eyre::eyre::Result;
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::{
+  prelude::*,
+  widgets::{block::*, *},
+};
+use syntect::{
+  easy::HighlightLines,
+  highlighting::Theme,
+  parsing::SyntaxSet,
+  util::LinesWithEndings,
+};
+
+use crate::{
+  action::Action,
+  components::spinner::Spinner,
+  panes::Pane,
+  tui::{EventResponse, Frame},
+};
+
+/// Shows the result of the request fired from `RequestPane::submit`, streamed
+/// back in as an `Action::Response` once the background task completes.
+#[derive(Default)]
+pub struct ResponsePane {
+  focused: bool,
+  focused_border_style: Style,
+  status: Option<u16>,
+  headers: Vec<(String, String)>,
+  content_type: Option<String>,
+  body_styles: Vec<Vec<(Style, String)>>,
+  body_line_offset: usize,
+  highlighter_syntax_set: SyntaxSet,
+  highlight_theme: Theme,
+  spinner: Spinner,
+  pending: bool,
+}
+
+impl ResponsePane {
+  pub fn new(focused: bool, focused_border_style: Style, highlight_theme: Theme) -> Self {
+    Self {
+      focused,
+      focused_border_style,
+      highlighter_syntax_set: SyntaxSet::load_defaults_newlines(),
+      highlight_theme,
+      ..Default::default()
+    }
+  }
+
+  fn border_style(&self) -> Style {
+    match self.focused {
+      true => self.focused_border_style,
+      false => Style::default(),
+    }
+  }
+
+  fn border_type(&self) -> BorderType {
+    match self.focused {
+      true => BorderType::Thick,
+      false => BorderType::Plain,
+    }
+  }
+
+  fn title(&self) -> String {
+    match (self.pending, self.status) {
+      (true, _) => format!("Response {}", self.spinner.frame()),
+      (false, Some(status)) => format!("Response [{status}] ({} headers)", self.headers.len()),
+      (false, None) => "Response".to_string(),
+    }
+  }
+
+  /// Picks a highlighting syntax from the response's content-type, falling
+  /// back to JSON since most OpenAPI responses are JSON.
+  fn syntax_token(&self) -> &str {
+    match self.content_type.as_deref() {
+      Some(content_type) if content_type.contains("yaml") => "yaml",
+      Some(content_type) if content_type.contains("xml") => "xml",
+      _ => "json",
+    }
+  }
+
+  fn set_body_styles(&mut self, body: &str) -> Result<()> {
+    self.body_styles.clear();
+    let syntax = self
+      .highlighter_syntax_set
+      .find_syntax_by_extension(self.syntax_token())
+      .unwrap_or_else(|| self.highlighter_syntax_set.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, &self.highlight_theme);
+    for (line_num, line) in LinesWithEndings::from(body).enumerate() {
+      let mut line_styles: Vec<(Style, String)> = highlighter
+        .highlight_line(line, &self.highlighter_syntax_set)?
+        .into_iter()
+        .map(|segment| {
+          (
+            syntect_tui::translate_style(segment.0).ok().unwrap_or_default().underline_color(Color::Reset).bg(Color::Reset),
+            segment.1.to_string(),
+          )
+        })
+        .collect();
+      line_styles.insert(0, (Style::default().dim(), format!(" {:<3} ", line_num + 1)));
+      self.body_styles.push(line_styles);
+    }
+    Ok(())
+  }
+}
+
+impl Pane for ResponsePane {
+  fn init(&mut self) -> Result<()> {
+    Ok(())
+  }
+
+  fn focus(&mut self) -> Result<()> {
+    self.focused = true;
+    Ok(())
+  }
+
+  fn unfocus(&mut self) -> Result<()> {
+    self.focused = false;
+    Ok(())
+  }
+
+  fn handle_key_events(&mut self, _key: KeyEvent) -> Result<Option<EventResponse<Action>>> {
+    Ok(None)
+  }
+
+  #[allow(unused_variables)]
+  fn handle_mouse_events(&mut self, mouse: MouseEvent) -> Result<Option<EventResponse<Action>>> {
+    Ok(None)
+  }
+
+  fn update(&mut self, action: Action) -> Result<Option<Action>> {
+    match action {
+      Action::Submit => {
+        self.pending = true;
+      },
+      Action::Response { status, headers, content_type, body } => {
+        self.pending = false;
+        self.spinner.reset();
+        self.status = Some(status);
+        self.headers = headers;
+        self.content_type = content_type;
+        self.body_line_offset = 0;
+        self.set_body_styles(&body)?;
+      },
+      Action::Error(_) => {
+        self.pending = false;
+        self.spinner.reset();
+      },
+      Action::SpinnerTick if self.pending => {
+        self.spinner.tick();
+      },
+      Action::Down => {
+        self.body_line_offset = self.body_line_offset.saturating_add(1).min(self.body_styles.len().saturating_sub(1));
+      },
+      Action::Up => {
+        self.body_line_offset = self.body_line_offset.saturating_sub(1);
+      },
+      _ => {},
+    }
+
+    Ok(None)
+  }
+
+  fn draw(&mut self, frame: &mut Frame<'_>, area: Rect) -> Result<()> {
+    let inner_margin: Margin = Margin { horizontal: 1, vertical: 1 };
+    let inner = area.inner(&inner_margin);
+
+    let lines = self.body_styles.iter().map(|items| {
+      Line::from(items.iter().map(|item| Span::styled(&item.1, item.0.bg(Color::Reset))).collect::<Vec<_>>())
+    });
+    let mut list_state = ListState::default().with_selected(Some(self.body_line_offset));
+
+    frame.render_stateful_widget(
+      List::new(lines).highlight_symbol(symbols::scrollbar::HORIZONTAL.end).highlight_spacing(HighlightSpacing::Always),
+      inner,
+      &mut list_state,
+    );
+
+    frame.render_widget(
+      Block::default()
+        .title(self.title())
+        .borders(Borders::ALL)
+        .border_style(self.border_style())
+        .border_type(self.border_type()),
+      area,
+    );
+
+    Ok(())
+  }
+}
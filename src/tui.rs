@@ -0,0 +1,136 @@
+use std::{
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
+
+use color_eyre::eyre::Result;
+use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, MouseEvent};
+use futures::StreamExt;
+use ratatui::backend::CrosstermBackend as Backend;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::action::Action;
+
+pub type IO = std::io::Stdout;
+pub fn io() -> IO {
+  std::io::stdout()
+}
+pub type Frame<'a> = ratatui::Frame<'a>;
+pub type Terminal = ratatui::Terminal<Backend<IO>>;
+
+pub const TICK_RATE: Duration = Duration::from_millis(16);
+
+/// Events produced by the terminal backend and fed into the app's update loop.
+/// `Tick` and `SpinnerTick` map onto the identically-named `Action` variants;
+/// everything else is translated by whatever's driving the pane tree.
+#[derive(Clone, Debug)]
+pub enum Event {
+  Init,
+  Quit,
+  Error,
+  Closed,
+  Tick,
+  SpinnerTick,
+  Render,
+  Key(KeyEvent),
+  Mouse(MouseEvent),
+  Resize(u16, u16),
+}
+
+/// How a component/pane wants an event to propagate once it has handled it.
+///
+/// `Stop` short-circuits further propagation up the pane tree (e.g. a parent
+/// page won't also react to the same key event), while `Continue` lets
+/// ancestors still see it after folding in the wrapped action.
+#[derive(Debug, Clone)]
+pub enum EventResponse<T> {
+  Stop(T),
+  Continue(T),
+}
+
+/// Terminal backend plus its background event-reader task.
+///
+/// The reader task multiplexes three sources into a single `Event` stream:
+/// crossterm input, a `TICK_RATE` heartbeat, and a `Spinner::TICK_RATE`
+/// heartbeat that only actually sends while `pending_tasks` is non-zero (see
+/// `note_action`), so idle panes never pay for spinner frames they won't draw.
+pub struct Tui {
+  pub terminal: Terminal,
+  event_rx: mpsc::UnboundedReceiver<Event>,
+  pending_tasks: Arc<AtomicUsize>,
+  task: JoinHandle<()>,
+}
+
+impl Tui {
+  pub fn new() -> Result<Self> {
+    let terminal = Terminal::new(Backend::new(io()))?;
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let pending_tasks = Arc::new(AtomicUsize::new(0));
+    let task = Self::spawn_event_reader(event_tx, pending_tasks.clone());
+    Ok(Self { terminal, event_rx, pending_tasks, task })
+  }
+
+  fn spawn_event_reader(event_tx: mpsc::UnboundedSender<Event>, pending_tasks: Arc<AtomicUsize>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+      let mut reader = EventStream::new();
+      let mut tick_interval = tokio::time::interval(TICK_RATE);
+      let mut spinner_interval = tokio::time::interval(crate::components::spinner::Spinner::TICK_RATE);
+
+      loop {
+        let crossterm_event = reader.next();
+        tokio::select! {
+          _ = tick_interval.tick() => {
+            let _ = event_tx.send(Event::Tick);
+          },
+          _ = spinner_interval.tick() => {
+            if pending_tasks.load(Ordering::Relaxed) > 0 {
+              let _ = event_tx.send(Event::SpinnerTick);
+            }
+          },
+          maybe_event = crossterm_event => {
+            match maybe_event {
+              Some(Ok(CrosstermEvent::Key(key))) => { let _ = event_tx.send(Event::Key(key)); },
+              Some(Ok(CrosstermEvent::Mouse(mouse))) => { let _ = event_tx.send(Event::Mouse(mouse)); },
+              Some(Ok(CrosstermEvent::Resize(w, h))) => { let _ = event_tx.send(Event::Resize(w, h)); },
+              Some(Ok(_)) => {},
+              Some(Err(_)) => { let _ = event_tx.send(Event::Error); },
+              None => {
+                let _ = event_tx.send(Event::Closed);
+                break;
+              },
+            }
+          },
+        }
+      }
+    })
+  }
+
+  pub async fn next(&mut self) -> Option<Event> {
+    self.event_rx.recv().await
+  }
+
+  /// Keeps the spinner-tick heartbeat honest: the app's main loop calls this
+  /// as it routes each `Action`, so `pending_tasks` reflects how many
+  /// requests are in flight (one per `Submit`, until its `Response`/`Error`).
+  /// Spec loading is not counted.
+  pub fn note_action(&self, action: &Action) {
+    match action {
+      Action::Submit => {
+        self.pending_tasks.fetch_add(1, Ordering::Relaxed);
+      },
+      Action::Response { .. } | Action::Error(_) => {
+        self.pending_tasks.fetch_sub(1, Ordering::Relaxed);
+      },
+      _ => {},
+    }
+  }
+}
+
+impl Drop for Tui {
+  fn drop(&mut self) {
+    self.task.abort();
+  }
+}
@@ -0,0 +1,166 @@
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Parser, Tag, TagEnd};
+use ratatui::prelude::*;
+use syntect::{easy::HighlightLines, highlighting::Theme, parsing::SyntaxSet};
+
+/// Render a CommonMark string (as found in OpenAPI `description` fields) into
+/// `ratatui` `Line`s.
+///
+/// This folds `pulldown_cmark`'s pull-parser `Event` stream into lines rather
+/// than building a DOM: a style stack is pushed on `Start(tag)` and popped on
+/// `End(tag)`, so inline styling nests the way CommonMark allows (e.g. bold
+/// text inside a list item). Fenced code blocks are highlighted with the same
+/// `syntect` `HighlightLines` pipeline `ResponsePane` uses for response
+/// bodies, so embedded JSON/YAML examples keep their colors.
+pub fn render(markdown: &str, syntax_set: &SyntaxSet, theme: &Theme) -> Vec<Line<'static>> {
+  let mut lines = vec![];
+  let mut current: Vec<Span<'static>> = vec![];
+  let mut style_stack: Vec<Style> = vec![Style::default()];
+  let mut list_depth: usize = 0;
+  let mut in_code_block = false;
+  let mut code_block_lang: Option<String> = None;
+  let mut code_block_buf = String::new();
+
+  let flush_line = |current: &mut Vec<Span<'static>>, lines: &mut Vec<Line<'static>>| {
+    lines.push(Line::from(std::mem::take(current)));
+  };
+
+  for event in Parser::new(markdown) {
+    match event {
+      Event::Start(Tag::Heading { level, .. }) => {
+        if !current.is_empty() {
+          flush_line(&mut current, &mut lines);
+        }
+        let style = Style::default().add_modifier(Modifier::BOLD).fg(match level {
+          HeadingLevel::H1 => Color::Cyan,
+          HeadingLevel::H2 => Color::Blue,
+          _ => Color::Magenta,
+        });
+        style_stack.push(style);
+      },
+      Event::End(TagEnd::Heading(_)) => {
+        style_stack.pop();
+        flush_line(&mut current, &mut lines);
+      },
+      Event::Start(Tag::Emphasis) => style_stack.push(style_stack.last().copied().unwrap_or_default().italic()),
+      Event::End(TagEnd::Emphasis) => {
+        style_stack.pop();
+      },
+      Event::Start(Tag::Strong) => {
+        style_stack.push(style_stack.last().copied().unwrap_or_default().add_modifier(Modifier::BOLD))
+      },
+      Event::End(TagEnd::Strong) => {
+        style_stack.pop();
+      },
+      Event::Start(Tag::Item) => {
+        list_depth += 1;
+        current.push(Span::raw(format!("{}\u{2022} ", "  ".repeat(list_depth.saturating_sub(1)))));
+      },
+      Event::End(TagEnd::Item) => {
+        list_depth = list_depth.saturating_sub(1);
+        flush_line(&mut current, &mut lines);
+      },
+      Event::Start(Tag::CodeBlock(kind)) => {
+        if !current.is_empty() {
+          flush_line(&mut current, &mut lines);
+        }
+        in_code_block = true;
+        code_block_lang = match kind {
+          CodeBlockKind::Fenced(lang) if !lang.is_empty() => Some(lang.to_string()),
+          _ => None,
+        };
+        code_block_buf.clear();
+      },
+      Event::End(TagEnd::CodeBlock) => {
+        let syntax = code_block_lang
+          .as_deref()
+          .and_then(|lang| syntax_set.find_syntax_by_token(lang))
+          .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, theme);
+        for code_line in code_block_buf.lines() {
+          let spans = highlighter
+            .highlight_line(code_line, syntax_set)
+            .ok()
+            .map(|segments| {
+              segments
+                .into_iter()
+                .map(|segment| {
+                  Span::styled(
+                    segment.1.to_string(),
+                    syntect_tui::translate_style(segment.0).ok().unwrap_or_default().bg(Color::Reset),
+                  )
+                })
+                .collect::<Vec<_>>()
+            })
+            .unwrap_or_else(|| vec![Span::raw(code_line.to_string())]);
+          lines.push(Line::from(spans));
+        }
+        in_code_block = false;
+        code_block_lang = None;
+      },
+      Event::Code(code) => {
+        current.push(Span::styled(code.to_string(), Style::default().bg(Color::DarkGray)));
+      },
+      Event::Text(text) => {
+        if in_code_block {
+          code_block_buf.push_str(&text);
+        } else {
+          current.push(Span::styled(text.to_string(), style_stack.last().copied().unwrap_or_default()));
+        }
+      },
+      Event::SoftBreak => current.push(Span::raw(" ")),
+      Event::HardBreak | Event::End(TagEnd::Paragraph) => flush_line(&mut current, &mut lines),
+      _ => {},
+    }
+  }
+
+  if !current.is_empty() {
+    flush_line(&mut current, &mut lines);
+  }
+
+  lines
+}
+
+#[cfg(test)]
+mod tests {
+  use syntect::highlighting::ThemeSet;
+
+  use super::*;
+
+  fn render_lines(markdown: &str) -> Vec<Line<'static>> {
+    let syntax_set = SyntaxSet::load_defaults_newlines();
+    let theme = ThemeSet::load_defaults().themes.get("Solarized (dark)").cloned().unwrap_or_default();
+    render(markdown, &syntax_set, &theme)
+  }
+
+  fn line_text(line: &Line<'static>) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+  }
+
+  #[test]
+  fn renders_a_heading_as_its_own_bold_line() {
+    let lines = render_lines("# Title\n\nBody text.");
+    assert_eq!(line_text(&lines[0]), "Title");
+    assert!(lines[0].spans[0].style.add_modifier.contains(Modifier::BOLD));
+  }
+
+  #[test]
+  fn renders_a_list_item_with_a_bullet_prefix() {
+    let lines = render_lines("- one\n- two");
+    assert_eq!(line_text(&lines[0]), "\u{2022} one");
+    assert_eq!(line_text(&lines[1]), "\u{2022} two");
+  }
+
+  #[test]
+  fn folds_fenced_code_block_text_into_separate_lines_without_the_fence() {
+    let lines = render_lines("```\nfirst\nsecond\n```");
+    let rendered: Vec<String> = lines.iter().map(line_text).collect();
+    assert_eq!(rendered, vec!["first".to_string(), "second".to_string()]);
+  }
+
+  #[test]
+  fn a_paragraph_ends_the_current_line() {
+    let lines = render_lines("para one\n\npara two");
+    assert_eq!(line_text(&lines[0]), "para one");
+    assert_eq!(line_text(&lines[1]), "para two");
+  }
+}
@@ -0,0 +1,30 @@
+pub mod markdown;
+
+use color_eyre::eyre::Result;
+use tracing_error::ErrorLayer;
+use tracing_subscriber::{self, layer::SubscriberExt, util::SubscriberInitExt};
+
+pub fn initialize_logging() -> Result<()> {
+  let file_appender =
+    tracing_appender::rolling::never(std::env::temp_dir(), format!("{}.log", env!("CARGO_PKG_NAME")));
+  let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
+  std::env::set_var("RUST_LOG", std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string()));
+  let file_subscriber = tracing_subscriber::fmt::layer()
+    .with_file(true)
+    .with_line_number(true)
+    .with_writer(non_blocking)
+    .with_target(false)
+    .with_ansi(false)
+    .with_filter(tracing_subscriber::filter::EnvFilter::from_default_env());
+  tracing_subscriber::registry().with(file_subscriber).with(ErrorLayer::default()).init();
+  Ok(())
+}
+
+pub fn initialize_panic_handler() -> Result<()> {
+  let (panic_hook, eyre_hook) = color_eyre::config::HookBuilder::default().into_hooks();
+  eyre_hook.install()?;
+  std::panic::set_hook(Box::new(move |panic_info| {
+    eprintln!("{}", panic_hook.panic_report(panic_info));
+  }));
+  Ok(())
+}